@@ -1,12 +1,127 @@
+use std::fmt::Display;
+
 pub fn join_strings<S>(strings: &[S]) -> String
 where
     S: AsRef<str>,
 {
-    strings
-        .iter()
-        .map(|s| s.as_ref())
-        .collect::<Vec<&str>>()
-        .join(", ")
+    Join::new(strings).sep(", ").build()
+}
+
+pub struct Join<'a, S> {
+    items: &'a [S],
+    sep: &'a str,
+    prefix: &'a str,
+    suffix: &'a str,
+}
+
+impl<'a, S> Join<'a, S>
+where
+    S: AsRef<str>,
+{
+    pub fn new(items: &'a [S]) -> Self {
+        Join {
+            items,
+            sep: ", ",
+            prefix: "",
+            suffix: "",
+        }
+    }
+
+    pub fn sep(mut self, sep: &'a str) -> Self {
+        self.sep = sep;
+        self
+    }
+
+    pub fn prefix(mut self, prefix: &'a str) -> Self {
+        self.prefix = prefix;
+        self
+    }
+
+    pub fn suffix(mut self, suffix: &'a str) -> Self {
+        self.suffix = suffix;
+        self
+    }
+
+    pub fn build(self) -> String {
+        let joined = self
+            .items
+            .iter()
+            .map(|s| s.as_ref())
+            .collect::<Vec<&str>>()
+            .join(self.sep);
+        format!("{}{}{}", self.prefix, joined, self.suffix)
+    }
+}
+
+pub fn join_display<T>(items: &[T], sep: &str) -> String
+where
+    T: Display,
+{
+    items.iter().enumerate().fold(String::new(), |mut acc, (i, item)| {
+        if i > 0 {
+            acc.push_str(sep);
+        }
+        acc.push_str(&item.to_string());
+        acc
+    })
+}
+
+pub fn split_map_join<'a>(input: &'a str, delim: &str, f: impl FnMut(&'a str) -> String) -> String {
+    split_map_join_as(input, delim, delim, f)
+}
+
+pub fn split_map_join_as<'a>(
+    input: &'a str,
+    delim: &str,
+    out_delim: &str,
+    f: impl FnMut(&'a str) -> String,
+) -> String {
+    input.split(delim).map(f).collect::<Vec<String>>().join(out_delim)
+}
+
+pub fn pipeline<'a, S>(items: &'a [S]) -> StringPipeline<'a>
+where
+    S: AsRef<str> + 'a,
+{
+    StringPipeline {
+        iter: Box::new(items.iter().map(|s| s.as_ref().to_string())),
+    }
+}
+
+pub struct StringPipeline<'a> {
+    iter: Box<dyn Iterator<Item = String> + 'a>,
+}
+
+impl<'a> StringPipeline<'a> {
+    pub fn lazy_map(self, f: impl Fn(&str) -> String + 'a) -> Self {
+        StringPipeline {
+            iter: Box::new(self.iter.map(move |s| f(&s))),
+        }
+    }
+
+    pub fn lazy_filter(self, f: impl Fn(&str) -> bool + 'a) -> Self {
+        StringPipeline {
+            iter: Box::new(self.iter.filter(move |s| f(s))),
+        }
+    }
+
+    pub fn join(self, sep: &str) -> String {
+        self.iter.collect::<Vec<String>>().join(sep)
+    }
+}
+
+pub fn repeat_join(token: &str, count: usize, sep: &str) -> String {
+    if count == 0 {
+        return String::new();
+    }
+
+    let mut result = String::with_capacity(count * (token.len() + sep.len()));
+    result.push_str(token);
+    for _ in 1..count {
+        result.push_str(sep);
+        result.push_str(token);
+    }
+    result
 }
 
 #[cfg(test)]
@@ -26,4 +141,107 @@ mod tests {
     fn join_strings_accept_vector() {
         assert_eq!(join_strings(&vec!["One", "Two"]), "One, Two");
     }
+
+    #[test]
+    fn join_display_accepts_numbers() {
+        assert_eq!(join_display(&[1, 2, 3], ","), "1,2,3");
+    }
+
+    #[test]
+    fn join_display_accepts_custom_separator() {
+        assert_eq!(join_display(&["One", "Two"], " - "), "One - Two");
+    }
+
+    #[test]
+    fn join_display_handles_empty_slice() {
+        assert_eq!(join_display::<i32>(&[], ","), "");
+    }
+
+    #[test]
+    fn join_display_keeps_separators_around_empty_elements() {
+        assert_eq!(join_display(&["", "x"], ","), ",x");
+        assert_eq!(join_display(&["", "", "val"], ","), ",,val");
+    }
+
+    #[test]
+    fn join_builder_custom_separator() {
+        assert_eq!(Join::new(&["One", "Two"]).sep("-").build(), "One-Two");
+    }
+
+    #[test]
+    fn join_builder_prefix_and_suffix() {
+        assert_eq!(
+            Join::new(&["a", "b", "c"]).prefix("[").suffix("]").build(),
+            "[a, b, c]"
+        );
+    }
+
+    #[test]
+    fn join_builder_defaults_match_join_strings() {
+        assert_eq!(Join::new(&["One", "Two"]).build(), join_strings(&["One", "Two"]));
+    }
+
+    #[test]
+    fn split_map_join_trims_and_rejoins() {
+        assert_eq!(
+            split_map_join("a\n, b , c\t", ",", |s| s.trim().to_string()),
+            "a,b,c"
+        );
+    }
+
+    #[test]
+    fn split_map_join_handles_empty_input() {
+        assert_eq!(split_map_join("", ",", |s| s.to_string()), "");
+    }
+
+    #[test]
+    fn split_map_join_handles_trailing_delimiter() {
+        assert_eq!(split_map_join("a,b,", ",", |s| s.to_string()), "a,b,");
+    }
+
+    #[test]
+    fn split_map_join_handles_absent_delimiter() {
+        assert_eq!(split_map_join("abc", ",", |s| s.to_uppercase()), "ABC");
+    }
+
+    #[test]
+    fn split_map_join_as_rewrites_delimiter() {
+        assert_eq!(
+            split_map_join_as("a,b,c", ",", "\t", |s| s.to_string()),
+            "a\tb\tc"
+        );
+    }
+
+    #[test]
+    fn pipeline_filters_maps_and_joins() {
+        let items = ["one", "", "two", "", "three"];
+        assert_eq!(
+            pipeline(&items)
+                .lazy_filter(|s| !s.is_empty())
+                .lazy_map(str::to_uppercase)
+                .join(", "),
+            "ONE, TWO, THREE"
+        );
+    }
+
+    #[test]
+    fn pipeline_with_no_combinators_matches_join_strings() {
+        let items = ["One", "Two"];
+        assert_eq!(pipeline(&items).join(", "), join_strings(&items));
+    }
+
+    #[test]
+    fn repeat_join_repeats_and_separates() {
+        assert_eq!(repeat_join("?", 3, ", "), "?, ?, ?");
+    }
+
+    #[test]
+    fn repeat_join_handles_zero_count() {
+        assert_eq!(repeat_join("?", 0, ", "), "");
+    }
+
+    #[test]
+    fn repeat_join_handles_single_count() {
+        assert_eq!(repeat_join("?", 1, ", "), "?");
+    }
 }
\ No newline at end of file